@@ -0,0 +1,213 @@
+//! Machine-readable diagnostics for `lint` and `compat` failures, so CI
+//! dashboards and code-scanning tools have something better than a
+//! `{:?}`-formatted error on stderr to work with.
+
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How a [`Diagnostic`] report is rendered.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Human,
+    Json,
+    Sarif,
+}
+
+/// The kind of problem a diagnostic reports, used as the SARIF `ruleId`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticKind {
+    ParseError,
+    MissingRequiredField,
+    RemovedSchema,
+    IncompatibleField,
+    /// A violation of a configurable rule from `rules`, named by its
+    /// `avrodisiac.toml` key (e.g. `"naming-convention"`).
+    RuleViolation { rule: String },
+}
+
+impl DiagnosticKind {
+    fn rule_id(&self) -> String {
+        match self {
+            DiagnosticKind::ParseError => "avrodisiac/parse-error".to_string(),
+            DiagnosticKind::MissingRequiredField => "avrodisiac/missing-required-field".to_string(),
+            DiagnosticKind::RemovedSchema => "avrodisiac/removed-schema".to_string(),
+            DiagnosticKind::IncompatibleField => "avrodisiac/incompatible-field".to_string(),
+            DiagnosticKind::RuleViolation { rule } => format!("avrodisiac/{rule}"),
+        }
+    }
+}
+
+/// The severity at which a diagnostic is reported.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warning,
+}
+
+/// A single lint or compatibility failure, carrying enough context (the
+/// file, the schema it concerns, and a location hint when one can be
+/// recovered from the underlying error) to annotate a PR directly.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    pub schema: Option<String>,
+    pub kind: DiagnosticKind,
+    pub message: String,
+    pub line: Option<usize>,
+    pub level: Level,
+}
+
+impl Diagnostic {
+    pub fn new(file: &Path, kind: DiagnosticKind, message: impl Into<String>) -> Self {
+        Diagnostic {
+            file: file.to_path_buf(),
+            schema: None,
+            kind,
+            message: message.into(),
+            line: None,
+            level: Level::Error,
+        }
+    }
+
+    pub fn with_schema(mut self, schema: impl Into<String>) -> Self {
+        self.schema = Some(schema.into());
+        self
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = level;
+        self
+    }
+}
+
+/// Prints `diagnostics` in `format`: JSON and SARIF reports go to stdout,
+/// the human-readable report goes to stderr.
+pub fn print_report(diagnostics: &[Diagnostic], format: OutputFormat) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Human => print_human(diagnostics),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(diagnostics)?),
+        OutputFormat::Sarif => println!("{}", serde_json::to_string_pretty(&to_sarif(diagnostics))?),
+    }
+    Ok(())
+}
+
+fn print_human(diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        let level = match diagnostic.level {
+            Level::Error => "error",
+            Level::Warning => "warning",
+        };
+        let schema = diagnostic
+            .schema
+            .as_deref()
+            .map(|s| format!(" ({s})"))
+            .unwrap_or_default();
+        let line = diagnostic
+            .line
+            .map(|l| format!(":{l}"))
+            .unwrap_or_default();
+        eprintln!(
+            "{level}: {}{}{}: {}",
+            diagnostic.file.display(),
+            line,
+            schema,
+            diagnostic.message
+        );
+    }
+}
+
+fn to_sarif(diagnostics: &[Diagnostic]) -> serde_json::Value {
+    let results: Vec<_> = diagnostics
+        .iter()
+        .map(|d| {
+            let mut region = serde_json::Map::new();
+            if let Some(line) = d.line {
+                region.insert("startLine".to_string(), serde_json::json!(line));
+            }
+            let level = match d.level {
+                Level::Error => "error",
+                Level::Warning => "warning",
+            };
+            serde_json::json!({
+                "ruleId": d.kind.rule_id(),
+                "level": level,
+                "message": { "text": d.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": d.file.to_string_lossy() },
+                        "region": region,
+                    }
+                }]
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "version": "2.1.0",
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "avrodisiac",
+                    "informationUri": "https://github.com/SwissDataScienceCenter/avrodisiac",
+                    "rules": [],
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_diagnostic_serializes_expected_shape() {
+        let diagnostic = Diagnostic::new(
+            Path::new("a.avsc"),
+            DiagnosticKind::RuleViolation { rule: "require-doc".to_string() },
+            "missing doc",
+        )
+        .with_schema("my.namespace.Test")
+        .with_line(3)
+        .with_level(Level::Warning);
+
+        let value = serde_json::to_value(&diagnostic).unwrap();
+        assert_eq!(value["file"], "a.avsc");
+        assert_eq!(value["schema"], "my.namespace.Test");
+        assert_eq!(value["message"], "missing doc");
+        assert_eq!(value["line"], 3);
+        assert_eq!(value["level"], "warning");
+        assert_eq!(value["kind"]["rule-violation"]["rule"], "require-doc");
+    }
+
+    #[test]
+    fn test_sarif_report_has_rule_id_and_artifact_uri() {
+        let diagnostics = vec![Diagnostic::new(
+            Path::new("schemas/test.avsc"),
+            DiagnosticKind::RuleViolation { rule: "naming-convention".to_string() },
+            "bad name",
+        )
+        .with_schema("Test")
+        .with_level(Level::Error)];
+
+        let sarif = to_sarif(&diagnostics);
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "avrodisiac/naming-convention");
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "schemas/test.avsc"
+        );
+    }
+}
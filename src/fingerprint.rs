@@ -0,0 +1,219 @@
+//! Avro Parsing Canonical Form (PCF) and schema fingerprinting.
+//!
+//! See https://avro.apache.org/docs/current/specification/#parsing-canonical-form-for-schemas
+//! and the accompanying fingerprinting algorithm.
+
+use anyhow::{Context, Result};
+use apache_avro::Schema;
+use serde_json::{Map, Value};
+
+const PRIMITIVE_TYPES: &[&str] = &[
+    "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+];
+
+/// Produces the Parsing Canonical Form of `schema`: only `type`, `name`,
+/// `fields`, `symbols`, `items`, `values` and `size` are retained, `doc`,
+/// `aliases`, `default` and logical-type attributes are stripped, every
+/// named type is fully qualified as `namespace.name`, and the result is
+/// serialized with no insignificant whitespace.
+pub fn parsing_canonical_form(schema: &Schema) -> Result<String> {
+    let value = serde_json::to_value(schema).context("failed to serialize schema to JSON")?;
+    let canonical = canonicalize_schema(&value, None);
+    Ok(serde_json::to_string(&canonical)?)
+}
+
+/// The 64-bit CRC-64-AVRO "Rabin" fingerprint of `bytes` (typically the PCF
+/// of a schema), per the Avro spec's `fingerprint64` algorithm.
+pub fn rabin_fingerprint(bytes: &[u8]) -> u64 {
+    const EMPTY: u64 = 0xc15d_213a_a4d7_a795;
+
+    let table = rabin_table();
+    let mut fp = EMPTY;
+    for &byte in bytes {
+        fp = (fp >> 8) ^ table[((fp ^ byte as u64) & 0xff) as usize];
+    }
+    fp
+}
+
+fn rabin_table() -> [u64; 256] {
+    const EMPTY: u64 = 0xc15d_213a_a4d7_a795;
+
+    let mut table = [0u64; 256];
+    for (i, slot) in table.iter_mut().enumerate() {
+        let mut fp = i as u64;
+        for _ in 0..8 {
+            fp = if fp & 1 == 1 { (fp >> 1) ^ EMPTY } else { fp >> 1 };
+        }
+        *slot = fp;
+    }
+    table
+}
+
+/// SHA-256 digest of `bytes`.
+pub fn sha256_fingerprint(bytes: &[u8]) -> Vec<u8> {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes).to_vec()
+}
+
+/// MD5 digest of `bytes`.
+pub fn md5_fingerprint(bytes: &[u8]) -> Vec<u8> {
+    use md5::{Digest, Md5};
+    Md5::digest(bytes).to_vec()
+}
+
+/// Renders `bytes` as a lowercase hex string.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn canonicalize_schema(value: &Value, enclosing_namespace: Option<&str>) -> Value {
+    match value {
+        Value::String(name) => Value::String(qualify_type_name(name, enclosing_namespace)),
+        Value::Array(union) => Value::Array(
+            union
+                .iter()
+                .map(|v| canonicalize_schema(v, enclosing_namespace))
+                .collect(),
+        ),
+        Value::Object(obj) => canonicalize_schema_object(obj, enclosing_namespace),
+        other => other.clone(),
+    }
+}
+
+fn canonicalize_schema_object(obj: &Map<String, Value>, enclosing_namespace: Option<&str>) -> Value {
+    let type_name = obj.get("type").and_then(Value::as_str).unwrap_or_default();
+    let namespace = obj
+        .get("namespace")
+        .and_then(Value::as_str)
+        .or(enclosing_namespace);
+
+    // A primitive carrying extra attributes (most commonly a `logicalType`)
+    // is still just that primitive in canonical form; `doc`/`logicalType`/etc.
+    // get stripped entirely rather than surviving on an otherwise-empty object.
+    if PRIMITIVE_TYPES.contains(&type_name) {
+        return Value::String(type_name.to_string());
+    }
+
+    let mut result = Map::new();
+    result.insert("type".to_string(), Value::String(type_name.to_string()));
+
+    match type_name {
+        "record" | "enum" | "fixed" => {
+            let name = obj.get("name").and_then(Value::as_str).unwrap_or_default();
+            let qualified = qualify(name, namespace);
+            let child_namespace = qualified.rfind('.').map(|i| qualified[..i].to_string());
+            result.insert("name".to_string(), Value::String(qualified));
+
+            match type_name {
+                "record" => {
+                    let fields = obj
+                        .get("fields")
+                        .and_then(Value::as_array)
+                        .cloned()
+                        .unwrap_or_default();
+                    let fields = fields
+                        .iter()
+                        .map(|f| canonicalize_field(f, child_namespace.as_deref()))
+                        .collect();
+                    result.insert("fields".to_string(), Value::Array(fields));
+                }
+                "enum" => {
+                    if let Some(symbols) = obj.get("symbols") {
+                        result.insert("symbols".to_string(), symbols.clone());
+                    }
+                }
+                "fixed" => {
+                    if let Some(size) = obj.get("size") {
+                        result.insert("size".to_string(), size.clone());
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        "array" => {
+            if let Some(items) = obj.get("items") {
+                result.insert("items".to_string(), canonicalize_schema(items, enclosing_namespace));
+            }
+        }
+        "map" => {
+            if let Some(values) = obj.get("values") {
+                result.insert("values".to_string(), canonicalize_schema(values, enclosing_namespace));
+            }
+        }
+        _ => {}
+    }
+
+    Value::Object(result)
+}
+
+fn canonicalize_field(field: &Value, namespace: Option<&str>) -> Value {
+    let Some(obj) = field.as_object() else {
+        return field.clone();
+    };
+    let mut result = Map::new();
+    if let Some(name) = obj.get("name") {
+        result.insert("name".to_string(), name.clone());
+    }
+    if let Some(field_type) = obj.get("type") {
+        result.insert("type".to_string(), canonicalize_schema(field_type, namespace));
+    }
+    Value::Object(result)
+}
+
+/// Fully qualifies `name` as `namespace.name` unless it is already qualified.
+fn qualify(name: &str, namespace: Option<&str>) -> String {
+    if name.contains('.') {
+        return name.to_string();
+    }
+    match namespace {
+        Some(ns) if !ns.is_empty() => format!("{ns}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+/// Like [`qualify`], but leaves Avro primitive type names untouched.
+fn qualify_type_name(name: &str, namespace: Option<&str>) -> String {
+    if PRIMITIVE_TYPES.contains(&name) {
+        name.to_string()
+    } else {
+        qualify(name, namespace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcf_strips_attributes_and_qualifies_names() {
+        let schema = Schema::parse_str(
+            r#"{
+               "type": "record",
+               "name": "Test",
+               "namespace": "my.namespace",
+               "doc": "a test record",
+               "fields": [
+                   { "name": "a", "doc": "a field", "type": "int", "default": 1 },
+                   { "name": "b", "type": { "type": "int", "logicalType": "date" } }
+               ]
+            }"#,
+        )
+        .unwrap();
+
+        let pcf = parsing_canonical_form(&schema).unwrap();
+        assert!(pcf.contains(r#""name":"my.namespace.Test""#));
+        assert!(pcf.contains(r#"{"name":"a","type":"int"}"#));
+        assert!(pcf.contains(r#"{"name":"b","type":"int"}"#));
+        assert!(!pcf.contains("doc"));
+        assert!(!pcf.contains("default"));
+        assert!(!pcf.contains("logicalType"));
+    }
+
+    #[test]
+    fn test_rabin_fingerprint_matches_avro_spec_reference_value() {
+        // The Avro spec's own reference fingerprint for the "null" schema.
+        let pcf = parsing_canonical_form(&Schema::parse_str(r#"{"type": "null"}"#).unwrap()).unwrap();
+        assert_eq!(pcf, r#""null""#);
+        assert_eq!(rabin_fingerprint(pcf.as_bytes()), 0x63dd_24e7_cc25_8f8a);
+    }
+}
@@ -1,12 +1,21 @@
 use std::{
+    collections::HashMap,
     ffi::OsStr,
     fs::{self},
     path::{Path, PathBuf},
 };
 
-use anyhow::{bail, Result};
-use apache_avro::{schema_compatibility::SchemaCompatibility, Schema};
-use clap::{arg, command, Parser, Subcommand};
+use anyhow::{bail, Context, Result};
+use apache_avro::{
+    schema::{Name, ResolvedSchema},
+    schema_compatibility::SchemaCompatibility,
+    Schema,
+};
+use clap::{arg, command, Parser, Subcommand, ValueEnum};
+
+mod diagnostics;
+mod fingerprint;
+mod rules;
 
 #[derive(Debug, Parser)]
 #[command(name = "avrodisiac")]
@@ -14,6 +23,9 @@ use clap::{arg, command, Parser, Subcommand};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Output format for lint/compat diagnostics.
+    #[arg(long, global = true, value_enum, default_value = "human")]
+    format: diagnostics::OutputFormat,
 }
 
 #[derive(Debug, Subcommand)]
@@ -28,11 +40,51 @@ enum Commands {
         old: PathBuf,
         #[arg(required = true)]
         new: PathBuf,
-        #[arg(short, long)]
-        mutual: bool,
+        #[arg(short, long, value_enum, default_value = "backward")]
+        mode: CompatibilityMode,
+    },
+    #[command(arg_required_else_help = true)]
+    Fingerprint {
+        #[arg(required = true)]
+        path: PathBuf,
+        #[arg(short, long, value_enum, default_value = "rabin")]
+        algorithm: FingerprintAlgorithm,
     },
 }
 
+/// The digest used to fingerprint a schema's Parsing Canonical Form, so it
+/// can be registered/compared against a schema registry by ID.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum FingerprintAlgorithm {
+    Rabin,
+    Sha256,
+    Md5,
+}
+
+/// Confluent-style schema-registry compatibility semantics. The `Transitive`
+/// variants check a candidate schema against every prior version of a
+/// subject rather than only the latest one.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum CompatibilityMode {
+    Backward,
+    Forward,
+    Full,
+    BackwardTransitive,
+    ForwardTransitive,
+    FullTransitive,
+}
+
+impl CompatibilityMode {
+    fn is_transitive(self) -> bool {
+        matches!(
+            self,
+            CompatibilityMode::BackwardTransitive
+                | CompatibilityMode::ForwardTransitive
+                | CompatibilityMode::FullTransitive
+        )
+    }
+}
+
 fn visit_dirs(dir: &Path) -> Result<Vec<PathBuf>> {
     let mut result = Vec::new();
     if dir.is_dir() {
@@ -54,62 +106,414 @@ fn visit_dirs(dir: &Path) -> Result<Vec<PathBuf>> {
     Ok(result)
 }
 
-fn parse_schemas(files: Vec<PathBuf>) -> Result<Vec<Schema>> {
-    let schemas: Vec<_> = files
-        .iter()
+/// A single `.avsc` file that failed to read or parse, with enough context
+/// to report it without aborting the rest of the tree.
+struct SchemaFileError {
+    file: PathBuf,
+    message: String,
+}
+
+impl std::fmt::Display for SchemaFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.file.display(), self.message)
+    }
+}
+
+/// The result of attempting to parse a set of `.avsc` files: every schema
+/// that parsed successfully (paired with the file it came from, so
+/// downstream diagnostics can point back at it), plus one [`SchemaFileError`]
+/// per file that didn't, so a single malformed file doesn't hide every other
+/// problem.
+struct ParsedSchemas {
+    schemas: Vec<Schema>,
+    schema_files: Vec<PathBuf>,
+    errors: Vec<SchemaFileError>,
+}
+
+fn parse_schemas(files: Vec<PathBuf>) -> ParsedSchemas {
+    let avsc_files: Vec<PathBuf> = files
+        .into_iter()
         .filter(|f| f.extension().is_some_and(|e| e == "avsc"))
-        .map(|f| String::from_utf8_lossy(&fs::read(f).expect("Unable to read file")).into_owned())
         .collect();
-    let schemas: Vec<&str> = schemas.iter().map(String::as_str).collect();
-    let parsed = Schema::parse_list(&schemas)?;
-    Ok(parsed)
+
+    let mut contents = Vec::new();
+    let mut errors = Vec::new();
+    for file in avsc_files {
+        match fs::read(&file) {
+            Ok(bytes) => contents.push((file, String::from_utf8_lossy(&bytes).into_owned())),
+            Err(err) => errors.push(SchemaFileError {
+                file,
+                message: format!("unable to read file: {err}"),
+            }),
+        }
+    }
+
+    let (schemas, schema_files, mut parse_errors) = parse_list_bisecting(contents);
+    errors.append(&mut parse_errors);
+    ParsedSchemas { schemas, schema_files, errors }
+}
+
+/// Parses `contents` together with [`Schema::parse_list`] so cross-file
+/// `Schema::Ref`s resolve. If the combined parse fails, bisects out
+/// individually-responsible files one at a time and retries, so one
+/// malformed file doesn't degrade every other file to independent parsing
+/// and break resolution of valid cross-file references among the rest.
+/// Returns the surviving schemas alongside the file each one came from.
+fn parse_list_bisecting(mut contents: Vec<(PathBuf, String)>) -> (Vec<Schema>, Vec<PathBuf>, Vec<SchemaFileError>) {
+    let mut errors = Vec::new();
+
+    loop {
+        if contents.is_empty() {
+            return (Vec::new(), Vec::new(), errors);
+        }
+
+        let refs: Vec<&str> = contents.iter().map(|(_, content)| content.as_str()).collect();
+        match Schema::parse_list(&refs) {
+            Ok(schemas) => {
+                let schema_files = contents.into_iter().map(|(file, _)| file).collect();
+                return (schemas, schema_files, errors);
+            }
+            Err(_) => match find_culprit(&contents) {
+                Some(index) => {
+                    let (file, content) = contents.remove(index);
+                    let message = Schema::parse_str(&content).err().map(|err| err.to_string()).unwrap_or_else(|| {
+                        "references a type that does not exist in this set of schemas".to_string()
+                    });
+                    errors.push(SchemaFileError { file, message });
+                }
+                None => {
+                    // No single file's removal fixes the combined parse;
+                    // fall back to parsing everything independently so the
+                    // failure is still reported rather than lost.
+                    let mut schemas = Vec::new();
+                    let mut schema_files = Vec::new();
+                    for (file, content) in contents {
+                        match Schema::parse_str(&content) {
+                            Ok(schema) => {
+                                schemas.push(schema);
+                                schema_files.push(file);
+                            }
+                            Err(err) => errors.push(SchemaFileError { file, message: err.to_string() }),
+                        }
+                    }
+                    return (schemas, schema_files, errors);
+                }
+            },
+        }
+    }
+}
+
+/// Finds the index of a file responsible for `contents` failing to parse
+/// together. The authoritative check is "does removing this file let the
+/// rest parse": a file that only fails to parse standalone because it
+/// legitimately references a named type defined elsewhere in the set (the
+/// exact cross-file case chunk0-3 added) is *not* a culprit even though
+/// `Schema::parse_str` rejects it alone, so that cheaper standalone check
+/// must never be preferred over this one. It's used only as a fallback when
+/// no single file's removal fixes the combined parse (e.g. more than one
+/// malformed file), to still make progress one file at a time.
+fn find_culprit(contents: &[(PathBuf, String)]) -> Option<usize> {
+    if let Some(index) = (0..contents.len()).find(|&index| {
+        let refs: Vec<&str> = contents
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, (_, content))| content.as_str())
+            .collect();
+        Schema::parse_list(&refs).is_ok()
+    }) {
+        return Some(index);
+    }
+
+    contents.iter().position(|(_, content)| Schema::parse_str(content).is_err())
+}
+
+fn ensure_no_parse_errors(parsed: &ParsedSchemas) -> Result<()> {
+    if parsed.errors.is_empty() {
+        return Ok(());
+    }
+    let messages: Vec<String> = parsed.errors.iter().map(SchemaFileError::to_string).collect();
+    bail!("{}", messages.join("\n"));
 }
 
-fn validate_schemas(path: &Path) -> Result<()> {
+/// Parses every schema under `path` and runs the configurable rule engine
+/// over each, aggregating all violations instead of bailing on the first.
+fn lint_schemas(path: &Path) -> Result<Vec<diagnostics::Diagnostic>> {
     let files = visit_dirs(path)?;
-    let _ = parse_schemas(files)?;
-    Ok(())
+    let parsed = parse_schemas(files);
+    let config = rules::discover_config(path)?;
+
+    let mut diagnostics: Vec<diagnostics::Diagnostic> = parsed
+        .errors
+        .iter()
+        .map(|e| diagnostics::Diagnostic::new(&e.file, diagnostic_kind_for_parse_error(&e.message), e.message.clone()))
+        .collect();
+
+    for (schema, file) in parsed.schemas.iter().zip(&parsed.schema_files) {
+        diagnostics.extend(rules::check_schema(schema, file, &config)?);
+    }
+    Ok(diagnostics)
 }
 
-fn compare_schemas(old: &Path, new: &Path, mutual: bool) -> Result<()> {
+/// Most `.avsc` files that fail to parse are just malformed JSON, but
+/// apache-avro also rejects otherwise well-formed JSON that's missing a
+/// field Avro requires (most commonly a record/enum/fixed's `name`); that
+/// case gets its own diagnostic kind so it reads as "fill in this field"
+/// rather than "your JSON is broken".
+fn diagnostic_kind_for_parse_error(message: &str) -> diagnostics::DiagnosticKind {
+    if message.contains("`name`") || message.to_lowercase().contains("missing field") {
+        diagnostics::DiagnosticKind::MissingRequiredField
+    } else {
+        diagnostics::DiagnosticKind::ParseError
+    }
+}
+
+/// Runs the `require-default-on-new-field` rule for every subject present
+/// in both `old` and `new`, if that rule is enabled in the discovered
+/// `avrodisiac.toml`.
+fn rule_diagnostics_for_compat(old: &Path, new: &Path) -> Result<Vec<diagnostics::Diagnostic>> {
+    let config = rules::discover_config(new)?;
+    if config.rules.require_default_on_new_field == rules::Severity::Off {
+        return Ok(Vec::new());
+    }
+
     let old_files = visit_dirs(old)?;
-    let old_schemas = parse_schemas(old_files)?;
+    let old_parsed = parse_schemas(old_files);
+    ensure_no_parse_errors(&old_parsed)?;
+    let old_index = schema_index(&old_parsed.schemas)?;
+
     let new_files = visit_dirs(new)?;
-    let new_schemas = parse_schemas(new_files)?;
-    for schema in old_schemas {
-        let new_schema = new_schemas
-            .iter()
-            .filter(|s| {
-                s.name().expect("no name on new schema")
-                    == schema.name().expect("no name on old schema")
-            })
-            .next();
-        match (new_schema, mutual) {
-            (Some(new_schema), true) => SchemaCompatibility::mutual_read(&schema, &new_schema)?,
-            (Some(new_schema), false) => SchemaCompatibility::can_read(&schema, &new_schema)?,
-            (None, _) => {
-                bail!("schema {:?} does not exist anymore", schema.name())
+    let new_parsed = parse_schemas(new_files);
+    ensure_no_parse_errors(&new_parsed)?;
+
+    let mut diagnostics = Vec::new();
+    for (new_schema, file) in new_parsed.schemas.iter().zip(&new_parsed.schema_files) {
+        let Some(name) = new_schema.name() else {
+            continue;
+        };
+        if let Some(old_schema) = old_index.get(name) {
+            diagnostics.extend(rules::check_new_fields_have_defaults(old_schema, new_schema, file, &config)?);
+        }
+    }
+    Ok(diagnostics)
+}
+
+/// Builds a name→schema index over `schemas` using apache-avro's
+/// schemata-aware resolution, so lookups land on fully-resolved trees
+/// instead of relying on `parse_list`'s ordering to have implicitly
+/// resolved every `Schema::Ref`.
+///
+/// `ResolvedSchema::try_from` resolves each schema's references against
+/// only the names it has already accepted, in slice order, so a schema
+/// that references a named type defined later in `schemas` (a likely
+/// outcome of `visit_dirs`'s unspecified directory traversal order) would
+/// otherwise fail to resolve even though the referenced type is present in
+/// the set. Reordering into a fixed point — repeatedly accepting whichever
+/// remaining schema resolves against what's already been accepted — makes
+/// the index insensitive to that input order.
+fn schema_index(schemas: &[Schema]) -> Result<HashMap<Name, &Schema>> {
+    let mut ordered: Vec<&Schema> = Vec::with_capacity(schemas.len());
+    let mut remaining: Vec<&Schema> = schemas.iter().collect();
+
+    while !remaining.is_empty() {
+        let progress = remaining.iter().position(|candidate| {
+            let mut attempt = ordered.clone();
+            attempt.push(candidate);
+            ResolvedSchema::try_from(attempt).is_ok()
+        });
+        match progress {
+            Some(index) => ordered.push(remaining.remove(index)),
+            None => break,
+        }
+    }
+    // Anything left genuinely doesn't resolve against the rest of the set;
+    // include it anyway so the real error (not an ordering artifact) surfaces.
+    ordered.extend(remaining);
+
+    let resolved = ResolvedSchema::try_from(ordered).context("failed to resolve named schema references")?;
+    Ok(resolved.get_names().clone())
+}
+
+fn compare_schemas(old: &Path, new: &Path, mode: CompatibilityMode) -> Result<()> {
+    let new_files = visit_dirs(new)?;
+    let new_parsed = parse_schemas(new_files);
+    ensure_no_parse_errors(&new_parsed)?;
+    let new_schemas = new_parsed.schemas;
+    let new_index = schema_index(&new_schemas)?;
+
+    if mode.is_transitive() {
+        for name in history_subject_names(old)? {
+            if !new_index.contains_key(&name) {
+                bail!("schema {name:?} does not exist anymore");
             }
         }
+        for candidate in &new_schemas {
+            check_against_history(old, candidate, mode)?;
+        }
+        return Ok(());
+    }
+
+    let old_files = visit_dirs(old)?;
+    let old_parsed = parse_schemas(old_files);
+    ensure_no_parse_errors(&old_parsed)?;
+    let old_schemas = old_parsed.schemas;
+    for schema in &old_schemas {
+        let name = schema.name().expect("no name on old schema");
+        match new_index.get(name) {
+            Some(new_schema) => check_compatibility(schema, new_schema, mode)?,
+            None => bail!("schema {:?} does not exist anymore", schema.name()),
+        }
     }
     Ok(())
 }
 
+/// Collects the distinct subject names that appear anywhere in
+/// `history_dir`, so a transitive compat check can tell a subject that's
+/// been removed entirely from a subject that simply hasn't changed.
+fn history_subject_names(history_dir: &Path) -> Result<Vec<Name>> {
+    let version_files: Vec<PathBuf> = visit_dirs(history_dir)?
+        .into_iter()
+        .filter(|f| f.extension().is_some_and(|e| e == "avsc"))
+        .collect();
+
+    let mut names = Vec::new();
+    for version_file in version_files {
+        let versions = parse_schemas(vec![version_file]);
+        ensure_no_parse_errors(&versions)?;
+        for schema in &versions.schemas {
+            if let Some(name) = schema.name() {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+    }
+    Ok(names)
+}
+
+/// Checks `candidate` against every prior version of its subject found in
+/// `history_dir`, ordered by filename, failing with the earliest
+/// incompatible version rather than only the most recent one.
+fn check_against_history(history_dir: &Path, candidate: &Schema, mode: CompatibilityMode) -> Result<()> {
+    let mut version_files: Vec<PathBuf> = visit_dirs(history_dir)?
+        .into_iter()
+        .filter(|f| f.extension().is_some_and(|e| e == "avsc"))
+        .collect();
+    version_files.sort();
+
+    let candidate_name = candidate.name().expect("no name on new schema");
+    for version_file in &version_files {
+        let versions = parse_schemas(vec![version_file.clone()]);
+        ensure_no_parse_errors(&versions)?;
+        let version_index = schema_index(&versions.schemas)?;
+        let Some(version_schema) = version_index.get(candidate_name) else {
+            continue;
+        };
+        check_compatibility(version_schema, candidate, mode)
+            .with_context(|| format!("incompatible with earlier version {version_file:?}"))?;
+    }
+    Ok(())
+}
+
+fn check_compatibility(old: &Schema, new: &Schema, mode: CompatibilityMode) -> Result<()> {
+    let compatible = match mode {
+        CompatibilityMode::Backward | CompatibilityMode::BackwardTransitive => {
+            SchemaCompatibility::can_read(old, new)
+        }
+        CompatibilityMode::Forward | CompatibilityMode::ForwardTransitive => {
+            SchemaCompatibility::can_read(new, old)
+        }
+        CompatibilityMode::Full | CompatibilityMode::FullTransitive => {
+            SchemaCompatibility::mutual_read(old, new)
+        }
+    };
+    if compatible {
+        Ok(())
+    } else {
+        bail!("schema {:?} is not {mode:?} compatible with {:?}", new.name(), old.name())
+    }
+}
+
+fn fingerprint_schemas(path: &Path, algorithm: FingerprintAlgorithm) -> Result<()> {
+    let files = visit_dirs(path)?;
+    let parsed = parse_schemas(files);
+    ensure_no_parse_errors(&parsed)?;
+    for schema in &parsed.schemas {
+        let name = schema.name().expect("schema must have a name").fullname(None);
+        let pcf = fingerprint::parsing_canonical_form(schema)?;
+        let digest = match algorithm {
+            FingerprintAlgorithm::Rabin => {
+                format!("{:016x}", fingerprint::rabin_fingerprint(pcf.as_bytes()))
+            }
+            FingerprintAlgorithm::Sha256 => {
+                fingerprint::to_hex(&fingerprint::sha256_fingerprint(pcf.as_bytes()))
+            }
+            FingerprintAlgorithm::Md5 => {
+                fingerprint::to_hex(&fingerprint::md5_fingerprint(pcf.as_bytes()))
+            }
+        };
+        println!("{name} {digest}");
+    }
+    Ok(())
+}
+
+/// Builds a [`diagnostics::Diagnostic`] out of an `anyhow::Error`, pulling a
+/// line-number location hint out of the underlying `serde_json::Error` when
+/// one is recoverable from the error chain.
+fn diagnostic_from_error(file: &Path, kind: diagnostics::DiagnosticKind, err: &anyhow::Error) -> diagnostics::Diagnostic {
+    let diagnostic = diagnostics::Diagnostic::new(file, kind, err.to_string());
+    match err.chain().find_map(|cause| cause.downcast_ref::<serde_json::Error>()) {
+        Some(json_err) => diagnostic.with_line(json_err.line()),
+        None => diagnostic,
+    }
+}
+
 fn main() -> Result<()> {
     let args = Cli::parse();
 
     match args.command {
-        Commands::Lint { path } => match validate_schemas(&path) {
+        Commands::Lint { path } => match lint_schemas(&path) {
+            Ok(diagnostics) => {
+                let has_errors = diagnostics.iter().any(|d| matches!(d.level, diagnostics::Level::Error));
+                if !diagnostics.is_empty() {
+                    diagnostics::print_report(&diagnostics, args.format)?;
+                }
+                if has_errors {
+                    std::process::exit(1);
+                }
+            }
             Err(err) => {
-                eprintln!("Schema(s) indalid: {:?}", err);
+                let diagnostic = diagnostic_from_error(&path, diagnostics::DiagnosticKind::ParseError, &err);
+                diagnostics::print_report(&[diagnostic], args.format)?;
                 std::process::exit(1);
             }
-            Ok(_) => {}
         },
-        Commands::Compat { old, new, mutual } => {
-            let compatible = compare_schemas(&old, &new, mutual);
-            if let Err(e) = compatible {
-                eprintln!("Schemas incompatible: {} [{:?}]", e, e.source());
+        Commands::Compat { old, new, mode } => {
+            let mut report = Vec::new();
+            if let Err(err) = compare_schemas(&old, &new, mode) {
+                let kind = if err.to_string().contains("does not exist anymore") {
+                    diagnostics::DiagnosticKind::RemovedSchema
+                } else {
+                    diagnostics::DiagnosticKind::IncompatibleField
+                };
+                report.push(diagnostic_from_error(&new, kind, &err));
+            }
+            match rule_diagnostics_for_compat(&old, &new) {
+                Ok(mut rule_diagnostics) => report.append(&mut rule_diagnostics),
+                Err(err) => report.push(diagnostic_from_error(&new, diagnostics::DiagnosticKind::ParseError, &err)),
+            }
+
+            let has_errors = report.iter().any(|d| matches!(d.level, diagnostics::Level::Error));
+            if !report.is_empty() {
+                diagnostics::print_report(&report, args.format)?;
+            }
+            if has_errors {
+                std::process::exit(1);
+            }
+        }
+        Commands::Fingerprint { path, algorithm } => {
+            if let Err(err) = fingerprint_schemas(&path, algorithm) {
+                eprintln!("Unable to fingerprint schema(s): {:?}", err);
                 std::process::exit(1);
             }
         }
@@ -132,45 +536,38 @@ mod tests {
     }
 
     #[test]
-    fn test_schema_validation() -> Result<()> {
+    fn test_lint_reports_missing_required_field() -> Result<()> {
         let dir = tempdir()?;
         create_file(
             &dir.path(),
             "test.avsc",
             r#"{
-               "name":"test",
                "namespace":"my.namespace",
                "type":"record",
                "fields":[
                    {
-                       "name":  "myField",
-                       "doc": "just a field",
                        "type":"int"
                    }
-               ] 
+               ]
             }"#,
         );
-        validate_schemas(&dir.path())?;
+        let diagnostics = lint_schemas(&dir.path())?;
+        assert!(diagnostics
+            .iter()
+            .any(|d| matches!(d.kind, diagnostics::DiagnosticKind::MissingRequiredField)));
         Ok(())
     }
+
     #[test]
-    fn test_invalid_schema_validation() -> Result<()> {
-        let dir = tempdir()?;
-        create_file(
-            &dir.path(),
-            "test.avsc",
-            r#"{
-               "namespace":"my.namespace",
-               "type":"record",
-               "fields":[
-                   {
-                       "type":"int"
-                   }
-               ] 
-            }"#,
-        );
-        assert!(validate_schemas(&dir.path()).is_err());
-        Ok(())
+    fn test_diagnostic_kind_for_parse_error_distinguishes_missing_field() {
+        assert!(matches!(
+            diagnostic_kind_for_parse_error("No `name` in record"),
+            diagnostics::DiagnosticKind::MissingRequiredField
+        ));
+        assert!(matches!(
+            diagnostic_kind_for_parse_error("expected value at line 1 column 1"),
+            diagnostics::DiagnosticKind::ParseError
+        ));
     }
 
     #[test]
@@ -216,7 +613,7 @@ mod tests {
                ] 
             }"#,
         );
-        compare_schemas(old_dir.path(), new_dir.path(), true)?;
+        compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Full)?;
         Ok(())
     }
 
@@ -263,7 +660,7 @@ mod tests {
                ] 
             }"#,
         );
-        assert!(compare_schemas(old_dir.path(), new_dir.path(), true).is_err());
+        assert!(compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Full).is_err());
         Ok(())
     }
     #[test]
@@ -303,7 +700,7 @@ mod tests {
                ] 
             }"#,
         );
-        compare_schemas(old_dir.path(), new_dir.path(), false)?;
+        compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Backward)?;
         Ok(())
     }
 
@@ -344,7 +741,31 @@ mod tests {
                ] 
             }"#,
         );
-        assert!(compare_schemas(old_dir.path(), new_dir.path(), false).is_err());
+        assert!(compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Backward).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_index_resolves_reference_before_definition() -> Result<()> {
+        let referencing = r#"{
+           "name":"test",
+           "namespace":"my.namespace",
+           "type":"record",
+           "fields":[
+               { "name": "nest", "type": "my.namespace.nested" }
+           ]
+        }"#;
+        let definition = r#"{
+           "name":"nested",
+           "namespace":"my.namespace",
+           "type":"record",
+           "fields":[
+               { "name": "myNestedField", "type":"int" }
+           ]
+        }"#;
+
+        let schemas = Schema::parse_list(&[referencing, definition])?;
+        schema_index(&schemas)?;
         Ok(())
     }
 
@@ -434,7 +855,7 @@ mod tests {
                ] 
             }"#,
         );
-        compare_schemas(old_dir.path(), new_dir.path(), true)?;
+        compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Full)?;
         Ok(())
     }
     #[test]
@@ -523,7 +944,309 @@ mod tests {
                ] 
             }"#,
         );
-        assert!(compare_schemas(old_dir.path(), new_dir.path(), true).is_err());
+        assert!(compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Full).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_forward_compatibility() -> Result<()> {
+        let old_dir = tempdir()?;
+        create_file(
+            &old_dir.path(),
+            "test.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   },
+                   {
+                       "name":  "myOtherField",
+                       "doc": "just a field",
+                       "type":"int",
+                       "default":1
+                   }
+               ]
+            }"#,
+        );
+
+        let new_dir = tempdir()?;
+        create_file(
+            &new_dir.path(),
+            "test.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+        compare_schemas(old_dir.path(), new_dir.path(), CompatibilityMode::Forward)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_backward_transitive_checks_every_version() -> Result<()> {
+        let history_dir = tempdir()?;
+        create_file(
+            &history_dir.path(),
+            "v1.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+        create_file(
+            &history_dir.path(),
+            "v2.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   },
+                   {
+                       "name":  "myOtherField",
+                       "doc": "just a field",
+                       "type":"int",
+                       "default":1
+                   }
+               ]
+            }"#,
+        );
+
+        // Readable against v2 but not v1: `myOtherField` has no default, so
+        // only a check that reaches the earlier version fails.
+        let new_dir = tempdir()?;
+        create_file(
+            &new_dir.path(),
+            "test.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   },
+                   {
+                       "name":  "myOtherField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+
+        assert!(
+            compare_schemas(history_dir.path(), new_dir.path(), CompatibilityMode::BackwardTransitive).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_backward_transitive_detects_removed_schema() -> Result<()> {
+        let history_dir = tempdir()?;
+        create_file(
+            &history_dir.path(),
+            "v1.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+
+        let new_dir = tempdir()?;
+        create_file(
+            &new_dir.path(),
+            "other.avsc",
+            r#"{
+               "name":"other",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+
+        assert!(
+            compare_schemas(history_dir.path(), new_dir.path(), CompatibilityMode::BackwardTransitive).is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_rules_off_by_default() -> Result<()> {
+        let dir = tempdir()?;
+        create_file(
+            &dir.path(),
+            "test.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+        assert!(lint_schemas(&dir.path())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_rules_from_config_file() -> Result<()> {
+        let dir = tempdir()?;
+        create_file(
+            &dir.path(),
+            "avrodisiac.toml",
+            r#"
+            [rules]
+            require-doc = "error"
+            naming-convention = "warn"
+            "#,
+        );
+        create_file(
+            &dir.path(),
+            "test.avsc",
+            r#"{
+               "name":"my_record",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+
+        let diagnostics = lint_schemas(&dir.path())?;
+        assert!(diagnostics
+            .iter()
+            .any(|d| matches!(d.level, diagnostics::Level::Error)));
+        assert!(diagnostics
+            .iter()
+            .any(|d| matches!(&d.kind, diagnostics::DiagnosticKind::RuleViolation { rule } if rule == "naming-convention")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_reports_parse_error_without_hiding_other_files() -> Result<()> {
+        let dir = tempdir()?;
+        create_file(
+            &dir.path(),
+            "good.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name":  "myField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+        create_file(&dir.path(), "bad.avsc", "{ not valid json");
+
+        let diagnostics = lint_schemas(&dir.path())?;
+        let parse_errors: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| matches!(d.kind, diagnostics::DiagnosticKind::ParseError))
+            .collect();
+        assert_eq!(parse_errors.len(), 1);
+        assert!(parse_errors[0].file.ends_with("bad.avsc"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lint_preserves_cross_file_refs_when_another_file_is_malformed() -> Result<()> {
+        let dir = tempdir()?;
+        create_file(
+            &dir.path(),
+            "a.avsc",
+            r#"{
+               "name":"nested",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name": "myNestedField",
+                       "doc": "just a field",
+                       "type":"int"
+                   }
+               ]
+            }"#,
+        );
+        create_file(
+            &dir.path(),
+            "b.avsc",
+            r#"{
+               "name":"test",
+               "namespace":"my.namespace",
+               "type":"record",
+               "fields":[
+                   {
+                       "name": "nest",
+                       "doc": "nested field",
+                       "type": "my.namespace.nested"
+                   }
+               ]
+            }"#,
+        );
+        create_file(&dir.path(), "bad.avsc", "{ not valid json");
+
+        let diagnostics = lint_schemas(&dir.path())?;
+        let parse_errors: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| matches!(d.kind, diagnostics::DiagnosticKind::ParseError))
+            .collect();
+        assert_eq!(parse_errors.len(), 1);
+        assert!(parse_errors[0].file.ends_with("bad.avsc"));
         Ok(())
     }
 }
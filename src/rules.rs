@@ -0,0 +1,363 @@
+//! Configurable lint rules loaded from an `avrodisiac.toml`, discovered by
+//! walking up the directory tree from the path being linted, so teams can
+//! opt into stylistic/structural checks on top of parse validity.
+
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use apache_avro::Schema;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::diagnostics::{Diagnostic, DiagnosticKind, Level};
+
+pub const CONFIG_FILE_NAME: &str = "avrodisiac.toml";
+
+const DEFAULT_NAMESPACE_PATTERN: &str = r"^[a-zA-Z][a-zA-Z0-9_]*(\.[a-zA-Z][a-zA-Z0-9_]*)*$";
+
+/// A rule's configured severity. `Off` (the default) means the rule does
+/// not run at all, so an unconfigured `avrodisiac.toml` lints exactly like
+/// today's parse-only behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warn,
+    #[default]
+    Off,
+}
+
+impl Severity {
+    fn level(self) -> Option<Level> {
+        match self {
+            Severity::Error => Some(Level::Error),
+            Severity::Warn => Some(Level::Warning),
+            Severity::Off => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Case {
+    Pascal,
+    Camel,
+    Snake,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct RuleSeverities {
+    pub require_doc: Severity,
+    pub naming_convention: Severity,
+    pub namespace_pattern: Severity,
+    pub forbid_bare_float: Severity,
+    pub require_default_on_new_field: Severity,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NamingConfig {
+    pub record_case: Case,
+    pub field_case: Case,
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        NamingConfig {
+            record_case: Case::Pascal,
+            field_case: Case::Camel,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NamespaceConfig {
+    pub pattern: String,
+}
+
+impl Default for NamespaceConfig {
+    fn default() -> Self {
+        NamespaceConfig {
+            pattern: DEFAULT_NAMESPACE_PATTERN.to_string(),
+        }
+    }
+}
+
+/// The fully-resolved rule configuration for a lint run.
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct Config {
+    pub rules: RuleSeverities,
+    pub naming: NamingConfig,
+    pub namespace: NamespaceConfig,
+}
+
+/// Walks up from `start` looking for an [`CONFIG_FILE_NAME`], returning the
+/// default (all rules off) config when none is found.
+pub fn discover_config(start: &Path) -> Result<Config> {
+    let mut dir = if start.is_dir() {
+        Some(start)
+    } else {
+        start.parent()
+    };
+    while let Some(candidate_dir) = dir {
+        let candidate = candidate_dir.join(CONFIG_FILE_NAME);
+        if candidate.is_file() {
+            let contents = fs::read_to_string(&candidate)
+                .with_context(|| format!("failed to read {candidate:?}"))?;
+            return toml::from_str(&contents).with_context(|| format!("failed to parse {candidate:?}"));
+        }
+        dir = candidate_dir.parent();
+    }
+    Ok(Config::default())
+}
+
+/// Runs every configured rule over `schema`, returning one diagnostic per
+/// violation found. `file` is the `.avsc` file `schema` was parsed from, so
+/// each diagnostic can point back at it.
+pub fn check_schema(schema: &Schema, file: &Path, config: &Config) -> Result<Vec<Diagnostic>> {
+    let value = serde_json::to_value(schema).context("failed to serialize schema to JSON")?;
+    let mut diagnostics = Vec::new();
+    walk(&value, file, None, config, &mut diagnostics);
+    Ok(diagnostics)
+}
+
+fn walk(value: &Value, file: &Path, enclosing_namespace: Option<&str>, config: &Config, out: &mut Vec<Diagnostic>) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                walk(item, file, enclosing_namespace, config, out);
+            }
+        }
+        Value::Object(obj) => {
+            let type_name = obj.get("type").and_then(Value::as_str).unwrap_or_default();
+            let namespace = obj.get("namespace").and_then(Value::as_str).or(enclosing_namespace);
+
+            match type_name {
+                "record" => {
+                    let name = obj.get("name").and_then(Value::as_str).unwrap_or("<unnamed>");
+                    check_record_name(file, name, namespace, config, out);
+                    if obj.get("doc").is_none() {
+                        push_rule_violation(
+                            file,
+                            config.rules.require_doc,
+                            "require-doc",
+                            name,
+                            format!("record `{name}` is missing a `doc`"),
+                            out,
+                        );
+                    }
+                    for field in obj.get("fields").and_then(Value::as_array).into_iter().flatten() {
+                        check_field(field, file, name, namespace, config, out);
+                    }
+                }
+                "enum" | "fixed" => {
+                    let name = obj.get("name").and_then(Value::as_str).unwrap_or("<unnamed>");
+                    check_record_name(file, name, namespace, config, out);
+                }
+                "array" => {
+                    if let Some(items) = obj.get("items") {
+                        walk(items, file, namespace, config, out);
+                    }
+                }
+                "map" => {
+                    if let Some(values) = obj.get("values") {
+                        walk(values, file, namespace, config, out);
+                    }
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+fn check_field(
+    field: &Value,
+    file: &Path,
+    record_name: &str,
+    namespace: Option<&str>,
+    config: &Config,
+    out: &mut Vec<Diagnostic>,
+) {
+    let Some(obj) = field.as_object() else {
+        return;
+    };
+    let field_name = obj.get("name").and_then(Value::as_str).unwrap_or("<unnamed>");
+    let has_doc = obj.get("doc").and_then(Value::as_str).is_some_and(|d| !d.is_empty());
+
+    if !has_doc {
+        push_rule_violation(
+            file,
+            config.rules.require_doc,
+            "require-doc",
+            record_name,
+            format!("field `{record_name}.{field_name}` is missing a `doc`"),
+            out,
+        );
+    }
+
+    if !matches_case(field_name, config.naming.field_case) {
+        push_rule_violation(
+            file,
+            config.rules.naming_convention,
+            "naming-convention",
+            record_name,
+            format!(
+                "field `{record_name}.{field_name}` does not follow {:?} case",
+                config.naming.field_case
+            ),
+            out,
+        );
+    }
+
+    let field_type_name = obj.get("type").and_then(Value::as_str);
+    if matches!(field_type_name, Some("float") | Some("double")) && !has_doc {
+        push_rule_violation(
+            file,
+            config.rules.forbid_bare_float,
+            "forbid-bare-float",
+            record_name,
+            format!(
+                "field `{record_name}.{field_name}` uses a bare `{}` without a documented rationale",
+                field_type_name.unwrap()
+            ),
+            out,
+        );
+    }
+
+    if let Some(field_type) = obj.get("type") {
+        walk(field_type, file, namespace, config, out);
+    }
+}
+
+fn check_record_name(file: &Path, name: &str, namespace: Option<&str>, config: &Config, out: &mut Vec<Diagnostic>) {
+    if !matches_case(name, config.naming.record_case) {
+        push_rule_violation(
+            file,
+            config.rules.naming_convention,
+            "naming-convention",
+            name,
+            format!("`{name}` does not follow {:?} case", config.naming.record_case),
+            out,
+        );
+    }
+
+    match Regex::new(&config.namespace.pattern) {
+        Ok(pattern) => match namespace {
+            Some(ns) if pattern.is_match(ns) => {}
+            Some(ns) => push_rule_violation(
+                file,
+                config.rules.namespace_pattern,
+                "namespace-pattern",
+                name,
+                format!("namespace `{ns}` on `{name}` does not match the configured pattern"),
+                out,
+            ),
+            None => push_rule_violation(
+                file,
+                config.rules.namespace_pattern,
+                "namespace-pattern",
+                name,
+                format!("`{name}` has no namespace"),
+                out,
+            ),
+        },
+        Err(_) => push_rule_violation(
+            file,
+            config.rules.namespace_pattern,
+            "namespace-pattern",
+            name,
+            "the configured namespace pattern is not a valid regex".to_string(),
+            out,
+        ),
+    }
+}
+
+/// Checks `new_schema`'s fields against `old_schema`'s: any field present in
+/// `new_schema` but not `old_schema` is a newly-added field, and must carry a
+/// `default` so existing readers of the old schema keep working.
+pub fn check_new_fields_have_defaults(
+    old_schema: &Schema,
+    new_schema: &Schema,
+    file: &Path,
+    config: &Config,
+) -> Result<Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+    if config.rules.require_default_on_new_field == Severity::Off {
+        return Ok(diagnostics);
+    }
+
+    let old_value = serde_json::to_value(old_schema).context("failed to serialize old schema to JSON")?;
+    let new_value = serde_json::to_value(new_schema).context("failed to serialize new schema to JSON")?;
+
+    let old_fields: Vec<&str> = old_value
+        .get("fields")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|f| f.get("name").and_then(Value::as_str))
+        .collect();
+
+    let new_name = new_value.get("name").and_then(Value::as_str).unwrap_or("<unnamed>");
+    for field in new_value.get("fields").and_then(Value::as_array).into_iter().flatten() {
+        let Some(field_name) = field.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        if old_fields.contains(&field_name) {
+            continue;
+        }
+        if field.get("default").is_none() {
+            push_rule_violation(
+                file,
+                config.rules.require_default_on_new_field,
+                "require-default-on-new-field",
+                new_name,
+                format!("newly-added field `{new_name}.{field_name}` has no `default`"),
+                &mut diagnostics,
+            );
+        }
+    }
+
+    Ok(diagnostics)
+}
+
+fn push_rule_violation(
+    file: &Path,
+    severity: Severity,
+    rule: &str,
+    schema_name: &str,
+    message: String,
+    out: &mut Vec<Diagnostic>,
+) {
+    let Some(level) = severity.level() else {
+        return;
+    };
+    out.push(
+        Diagnostic::new(file, DiagnosticKind::RuleViolation { rule: rule.to_string() }, message)
+            .with_schema(schema_name)
+            .with_level(level),
+    );
+}
+
+fn matches_case(s: &str, case: Case) -> bool {
+    if s.is_empty() {
+        return false;
+    }
+    match case {
+        Case::Pascal => {
+            s.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+                && s.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+        Case::Camel => {
+            s.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+                && s.chars().all(|c| c.is_ascii_alphanumeric())
+        }
+        Case::Snake => {
+            !s.starts_with('_')
+                && s.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        }
+    }
+}